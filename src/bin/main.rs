@@ -8,6 +8,9 @@ use esp_hal::time::{Duration, Instant, Rate};
 use esp_hal::gpio::{Level, Output, OutputConfig};
 use esp_hal::i2c::master::{I2c, Config as I2cConfig};
 use embedded_hal::i2c::I2c as I2cTrait;
+use embedded_hal_async::i2c::I2c as I2cAsyncTrait;
+use embedded_hal_async::delay::DelayNs as DelayNsAsyncTrait;
+use core::fmt::Write as _;
 
 #[panic_handler]
 fn panic(_: &core::panic::PanicInfo) -> ! {
@@ -39,6 +42,11 @@ const LCD_CURSOROFF: u8 = 0x00;
 const LCD_BLINKON: u8 = 0x01;
 const LCD_BLINKOFF: u8 = 0x00;
 
+const LCD_DISPLAYMOVE: u8 = 0x08;
+const LCD_CURSORMOVE: u8 = 0x00;
+const LCD_MOVERIGHT: u8 = 0x04;
+const LCD_MOVELEFT: u8 = 0x00;
+
 const LCD_8BITMODE: u8 = 0x10;
 const LCD_4BITMODE: u8 = 0x00;
 const LCD_2LINE: u8 = 0x08;
@@ -51,34 +59,147 @@ const RW: u8 = 0x02;
 const EN: u8 = 0x04;
 const BACKLIGHT: u8 = 0x08;
 
+/// How long to wait after a command before the controller is ready for
+/// the next one. [`LcdI2c`] and [`LcdI2cAsync`] both consult this so the
+/// blocking/async timing stays in lockstep.
+enum SettleDelay {
+    Millis(u64),
+    Micros(u64),
+}
+
+/// How long to settle after issuing `cmd`, per the HD44780 datasheet
+/// (clear/home need the long internal-clear cycle, everything else is
+/// done well within 100us).
+fn command_settle_delay(cmd: u8) -> SettleDelay {
+    match cmd {
+        LCD_CLEARDISPLAY | LCD_RETURNHOME => SettleDelay::Millis(5),
+        _ => SettleDelay::Micros(200),
+    }
+}
+
+/// Splits a command byte into the high/low PCF8574 nibble bytes (RS low),
+/// OR'd with the current backlight bit. Shared by the blocking and async
+/// drivers so there is one source of truth for the HD44780 wire protocol.
+fn command_nibbles(cmd: u8, backlight_state: u8) -> (u8, u8) {
+    let high = (cmd & 0xF0) | backlight_state;
+    let low = ((cmd << 4) & 0xF0) | backlight_state;
+    (high, low)
+}
+
+/// Splits a data byte into the high/low PCF8574 nibble bytes with RS set,
+/// OR'd with the current backlight bit.
+fn data_nibbles(data: u8, backlight_state: u8) -> (u8, u8) {
+    let high = (data & 0xF0) | backlight_state | RS;
+    let low = ((data << 4) & 0xF0) | backlight_state | RS;
+    (high, low)
+}
+
+/// Physical dimensions of an HD44780-compatible character display.
+///
+/// DDRAM row offsets follow the controller's row-wrap convention: the
+/// third and fourth rows of a 4-line display continue directly after the
+/// first and second rows rather than occupying their own address block.
+#[derive(Clone, Copy)]
+struct LcdGeometry {
+    cols: u8,
+    rows: u8,
+}
+
+impl LcdGeometry {
+    /// Panics if `cols` or `rows` is zero — `advance_line` divides by
+    /// `rows` on every line wrap, and the panic handler just loops
+    /// forever, so a zero dimension must never reach a live driver.
+    const fn new(cols: u8, rows: u8) -> Self {
+        assert!(cols > 0 && rows > 0, "LcdGeometry requires at least 1 column and 1 row");
+        Self { cols, rows }
+    }
+
+    fn row_offset(&self, row: u8) -> u8 {
+        match row {
+            0 => 0x00,
+            1 => 0x40,
+            2 => self.cols,
+            _ => 0x40 + self.cols,
+        }
+    }
+}
+
+/// Errors returned by [`LcdI2c`] operations.
+///
+/// `Bus` preserves the underlying I2C fault (e.g. a NACK or arbitration
+/// loss on a shared bus) instead of discarding it, so callers can match
+/// on the specific failure rather than receiving an opaque string.
+// TODO: derive `defmt::Format` behind an optional `defmt` feature once
+// this crate has a Cargo.toml to declare it in.
+#[derive(Debug)]
+enum LcdError<E> {
+    /// No LCD responded at any of the known I2C addresses.
+    NotFound,
+    /// Initialization did not complete within the allotted retries.
+    InitFailed,
+    /// A CGRAM slot index outside the valid `0..8` range was requested.
+    InvalidSlot,
+    /// The underlying I2C transaction failed.
+    Bus(E),
+}
+
+/// `I2C` only needs to implement `embedded_hal::i2c::I2c`, not own the
+/// whole peripheral, so the bus can be shared with other devices (e.g. a
+/// Si5351 clock generator or an LTR-559 light sensor) by wrapping it in
+/// an `embedded-hal-bus` shared-bus device (`RefCellDevice`, `MutexDevice`,
+/// ...) and handing each driver its own `I2cDevice`.
 struct LcdI2c<I2C> {
     i2c: I2C,
     address: u8,
     backlight_state: u8,
+    geometry: LcdGeometry,
+    cursor_col: u8,
+    cursor_row: u8,
+    display_control: u8,
+    entry_mode: u8,
 }
 
 impl<I2C> LcdI2c<I2C>
 where
     I2C: I2cTrait,
 {
-    fn new(mut i2c: I2C) -> Result<Self, &'static str> {
-        let mut found_address = None;
-        
-        for &addr in &LCD_ADDRESSES {
-            if i2c.write(addr, &[0x00]).is_ok() {
-                found_address = Some(addr);
-                break;
-            }
-        }
-        
-        let address = found_address.ok_or("No LCD found")?;
-        
+    /// Probes the default I2C addresses and initializes a 16x2 display.
+    fn new(i2c: I2C) -> Result<Self, LcdError<I2C::Error>> {
+        Self::new_with_geometry(i2c, LcdGeometry::new(16, 2))
+    }
+
+    /// Probes the default I2C addresses and initializes a display of the
+    /// given geometry, e.g. `LcdGeometry::new(20, 4)` for a 20x4 module.
+    fn new_with_geometry(mut i2c: I2C, geometry: LcdGeometry) -> Result<Self, LcdError<I2C::Error>> {
+        let address = Self::probe_address(&mut i2c).ok_or(LcdError::NotFound)?;
+        Self::new_with_address_and_geometry(i2c, address, geometry)
+    }
+
+    /// Initializes a 16x2 display at a known address, skipping the probe
+    /// scan entirely — the only option that is guaranteed not to touch
+    /// other devices on a shared bus.
+    fn new_with_address(i2c: I2C, address: u8) -> Result<Self, LcdError<I2C::Error>> {
+        Self::new_with_address_and_geometry(i2c, address, LcdGeometry::new(16, 2))
+    }
+
+    /// Initializes a display of the given geometry at a known address,
+    /// skipping the probe scan entirely.
+    fn new_with_address_and_geometry(
+        i2c: I2C,
+        address: u8,
+        geometry: LcdGeometry,
+    ) -> Result<Self, LcdError<I2C::Error>> {
         let mut lcd = LcdI2c {
             i2c,
             address,
             backlight_state: BACKLIGHT,
+            geometry,
+            cursor_col: 0,
+            cursor_row: 0,
+            display_control: LCD_DISPLAYON | LCD_CURSOROFF | LCD_BLINKOFF,
+            entry_mode: LCD_ENTRYLEFT | LCD_ENTRYSHIFTDECREMENT,
         };
-        
+
         for attempt in 0..3 {
             if lcd.init().is_ok() {
                 return Ok(lcd);
@@ -86,10 +207,20 @@ where
             lcd.delay_ms(100 * (attempt + 1) as u64);
         }
         
-        Err("LCD init failed after 3 attempts")
+        Err(LcdError::InitFailed)
     }
-    
-    fn init(&mut self) -> Result<(), &'static str> {
+
+    /// Finds the first responding address via a non-destructive
+    /// zero-length write, so other devices sharing the bus are left
+    /// undisturbed by the scan.
+    fn probe_address(i2c: &mut I2C) -> Option<u8> {
+        LCD_ADDRESSES
+            .iter()
+            .copied()
+            .find(|&addr| i2c.write(addr, &[]).is_ok())
+    }
+
+    fn init(&mut self) -> Result<(), LcdError<I2C::Error>> {
         self.delay_ms(200);
         
         self.backlight_test()?;
@@ -123,13 +254,13 @@ where
         self.send_command(LCD_DISPLAYCONTROL | LCD_DISPLAYOFF)?;
         self.send_command(LCD_CLEARDISPLAY)?;
         self.delay_ms(10);
-        self.send_command(LCD_ENTRYMODESET | LCD_ENTRYLEFT | LCD_ENTRYSHIFTDECREMENT)?;
-        self.send_command(LCD_DISPLAYCONTROL | LCD_DISPLAYON | LCD_CURSOROFF | LCD_BLINKOFF)?;
-        
+        self.send_command(LCD_ENTRYMODESET | self.entry_mode)?;
+        self.send_command(LCD_DISPLAYCONTROL | self.display_control)?;
+
         Ok(())
     }
     
-    fn backlight_test(&mut self) -> Result<(), &'static str> {
+    fn backlight_test(&mut self) -> Result<(), LcdError<I2C::Error>> {
         self.write_raw(0x00)?;
         self.delay_ms(100);
         self.write_raw(BACKLIGHT)?;
@@ -137,11 +268,11 @@ where
         Ok(())
     }
     
-    fn write_raw(&mut self, data: u8) -> Result<(), &'static str> {
-        self.i2c.write(self.address, &[data]).map_err(|_| "I2C write failed")
+    fn write_raw(&mut self, data: u8) -> Result<(), LcdError<I2C::Error>> {
+        self.i2c.write(self.address, &[data]).map_err(LcdError::Bus)
     }
     
-    fn pulse_enable_raw(&mut self, data: u8) -> Result<(), &'static str> {
+    fn pulse_enable_raw(&mut self, data: u8) -> Result<(), LcdError<I2C::Error>> {
         self.write_raw(data | EN)?;
         self.delay_us(2);
         self.write_raw(data & !EN)?;
@@ -149,58 +280,106 @@ where
         Ok(())
     }
     
-    fn send_command(&mut self, cmd: u8) -> Result<(), &'static str> {
-        let high = (cmd & 0xF0) | self.backlight_state;
+    fn send_command(&mut self, cmd: u8) -> Result<(), LcdError<I2C::Error>> {
+        let (high, low) = command_nibbles(cmd, self.backlight_state);
         self.write_raw(high)?;
         self.pulse_enable_raw(high)?;
-        
-        let low = ((cmd << 4) & 0xF0) | self.backlight_state;
+
         self.write_raw(low)?;
         self.pulse_enable_raw(low)?;
-        
-        match cmd {
-            LCD_CLEARDISPLAY | LCD_RETURNHOME => self.delay_ms(5),
-            _ => self.delay_us(200),
+
+        match command_settle_delay(cmd) {
+            SettleDelay::Millis(ms) => self.delay_ms(ms),
+            SettleDelay::Micros(us) => self.delay_us(us),
         }
         Ok(())
     }
-    
-    fn send_data(&mut self, data: u8) -> Result<(), &'static str> {
-        let high = (data & 0xF0) | self.backlight_state | RS;
+
+    fn send_data(&mut self, data: u8) -> Result<(), LcdError<I2C::Error>> {
+        let (high, low) = data_nibbles(data, self.backlight_state);
         self.write_raw(high)?;
         self.pulse_enable_raw(high)?;
-        
-        let low = ((data << 4) & 0xF0) | self.backlight_state | RS;
+
         self.write_raw(low)?;
         self.pulse_enable_raw(low)?;
-        
+
         self.delay_us(200);
         Ok(())
     }
     
-    fn clear(&mut self) -> Result<(), &'static str> {
+    fn clear(&mut self) -> Result<(), LcdError<I2C::Error>> {
         self.send_command(LCD_CLEARDISPLAY)?;
         self.delay_ms(5);
+        self.cursor_col = 0;
+        self.cursor_row = 0;
         Ok(())
     }
     
-    fn set_cursor(&mut self, col: u8, row: u8) -> Result<(), &'static str> {
-        let row_offsets = [0x00, 0x40];
-        if row < 2 && col < 16 {
-            let pos = col + row_offsets[row as usize];
+    fn set_cursor(&mut self, col: u8, row: u8) -> Result<(), LcdError<I2C::Error>> {
+        if row < self.geometry.rows && col < self.geometry.cols {
+            let pos = col + self.geometry.row_offset(row);
             self.send_command(LCD_SETDDRAMADDR | pos)?;
+            self.cursor_col = col;
+            self.cursor_row = row;
         }
         Ok(())
     }
-    
-    fn print(&mut self, text: &str) -> Result<(), &'static str> {
+
+    /// Advances the tracked cursor position to the start of the next line,
+    /// wrapping back to row 0 once past the last row.
+    fn advance_line(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        let next_row = (self.cursor_row + 1) % self.geometry.rows;
+        self.set_cursor(0, next_row)
+    }
+
+    /// Emits one character and keeps `cursor_col`/`cursor_row` in sync,
+    /// auto-wrapping at the configured width. This is the single place
+    /// that advances the tracked cursor, so `print`/`print_at` and the
+    /// `core::fmt::Write` impl never disagree about where it is.
+    fn put_char(&mut self, byte: u8) -> Result<(), LcdError<I2C::Error>> {
+        if byte == b'\n' {
+            return self.advance_line();
+        }
+
+        self.send_data(byte)?;
+        self.cursor_col += 1;
+
+        if self.cursor_col >= self.geometry.cols {
+            self.advance_line()?;
+        }
+        Ok(())
+    }
+
+    /// Loads a user-defined 5x8 glyph into one of the eight CGRAM slots.
+    ///
+    /// `pattern` holds one byte per glyph row; only the low 5 bits of each
+    /// byte are significant. The previous DDRAM cursor position is saved
+    /// and restored afterwards so printing genuinely resumes where it
+    /// left off. Use `send_data(slot)` to display it.
+    fn create_char(&mut self, slot: u8, pattern: &[u8; 8]) -> Result<(), LcdError<I2C::Error>> {
+        if slot >= 8 {
+            return Err(LcdError::InvalidSlot);
+        }
+
+        let (prev_col, prev_row) = (self.cursor_col, self.cursor_row);
+
+        self.send_command(LCD_SETCGRAMADDR | (slot << 3))?;
+        for &row in pattern {
+            self.send_data(row & 0x1F)?;
+        }
+
+        self.set_cursor(prev_col, prev_row)?;
+        Ok(())
+    }
+
+    fn print(&mut self, text: &str) -> Result<(), LcdError<I2C::Error>> {
         for byte in text.bytes() {
-            self.send_data(byte)?;
+            self.put_char(byte)?;
         }
         Ok(())
     }
     
-    fn print_at(&mut self, col: u8, row: u8, text: &str) -> Result<(), &'static str> {
+    fn print_at(&mut self, col: u8, row: u8, text: &str) -> Result<(), LcdError<I2C::Error>> {
         self.set_cursor(col, row)?;
         self.print(text)
     }
@@ -208,26 +387,73 @@ where
     fn get_address(&self) -> u8 {
         self.address
     }
-    
-    fn test_display(&mut self) -> Result<(), &'static str> {
+
+    /// Turns the display on or off without affecting cursor/blink state.
+    fn set_display(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.set_display_control_bit(LCD_DISPLAYON, on)
+    }
+
+    /// Shows or hides the underline cursor.
+    fn set_cursor_visible(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.set_display_control_bit(LCD_CURSORON, on)
+    }
+
+    /// Enables or disables the blinking block cursor.
+    fn set_blink(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.set_display_control_bit(LCD_BLINKON, on)
+    }
+
+    /// Enables or disables autoscroll on write (shifts existing text instead
+    /// of advancing the cursor).
+    fn set_autoscroll(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.entry_mode = if on {
+            self.entry_mode | LCD_ENTRYSHIFTINCREMENT
+        } else {
+            self.entry_mode & !LCD_ENTRYSHIFTINCREMENT
+        };
+        self.send_command(LCD_ENTRYMODESET | self.entry_mode)
+    }
+
+    /// Shifts the whole display one position to the left.
+    fn scroll_display_left(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.send_command(LCD_CURSORSHIFT | LCD_DISPLAYMOVE | LCD_MOVELEFT)
+    }
+
+    /// Shifts the whole display one position to the right.
+    fn scroll_display_right(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.send_command(LCD_CURSORSHIFT | LCD_DISPLAYMOVE | LCD_MOVERIGHT)
+    }
+
+    /// Sets or clears a bit in the cached display-control byte and re-sends
+    /// the composite command so the other flags are left untouched.
+    fn set_display_control_bit(&mut self, bit: u8, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.display_control = if on {
+            self.display_control | bit
+        } else {
+            self.display_control & !bit
+        };
+        self.send_command(LCD_DISPLAYCONTROL | self.display_control)
+    }
+
+    fn test_display(&mut self) -> Result<(), LcdError<I2C::Error>> {
         self.clear()?;
-        
-        self.set_cursor(0, 0)?;
-        for _ in 0..16 {
-            self.send_data(b'A')?;
-        }
-        
-        self.set_cursor(0, 1)?;
-        for _ in 0..16 {
-            self.send_data(b'B')?;
+
+        for row in 0..self.geometry.rows {
+            self.set_cursor(0, row)?;
+            let fill = b'A' + row;
+            for _ in 0..self.geometry.cols {
+                self.send_data(fill)?;
+            }
         }
-        
+
         self.delay_ms(2000);
-        
+
         self.clear()?;
         self.print_at(0, 0, "0123456789ABCDEF")?;
-        self.print_at(0, 1, "Test Pattern OK!")?;
-        
+        if self.geometry.rows > 1 {
+            self.print_at(0, 1, "Test Pattern OK!")?;
+        }
+
         Ok(())
     }
     
@@ -242,6 +468,356 @@ where
     }
 }
 
+impl<I2C> core::fmt::Write for LcdI2c<I2C>
+where
+    I2C: I2cTrait,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        for byte in s.bytes() {
+            self.put_char(byte).map_err(|_| core::fmt::Error)?;
+        }
+        Ok(())
+    }
+}
+
+/// Non-blocking twin of [`LcdI2c`], carrying the same public surface
+/// (geometry, CGRAM glyphs, display/cursor control, text writes) for
+/// use under an async executor.
+///
+/// Every wait in the HD44780 init sequence and the inter-command delays
+/// is an `.await` on an injected [`embedded_hal_async::delay::DelayNs`]
+/// instead of a busy spin, so the LCD can share a core with other async
+/// tasks. The nibble/timing math is shared with the blocking driver via
+/// [`command_nibbles`], [`data_nibbles`] and [`command_settle_delay`] so
+/// there is a single source of truth for the wire protocol. Text output
+/// is exposed as the inherent [`Self::write_str`], which takes an
+/// already-formatted `&str`; there is no `core::fmt::Write` impl, since
+/// that trait's `write_str` is synchronous and cannot `.await`.
+struct LcdI2cAsync<I2C, D> {
+    i2c: I2C,
+    delay: D,
+    address: u8,
+    backlight_state: u8,
+    geometry: LcdGeometry,
+    cursor_col: u8,
+    cursor_row: u8,
+    display_control: u8,
+    entry_mode: u8,
+}
+
+impl<I2C, D> LcdI2cAsync<I2C, D>
+where
+    I2C: I2cAsyncTrait,
+    D: DelayNsAsyncTrait,
+{
+    /// Probes the default I2C addresses and initializes a 16x2 display.
+    async fn new(i2c: I2C, delay: D) -> Result<Self, LcdError<I2C::Error>> {
+        Self::new_with_geometry(i2c, delay, LcdGeometry::new(16, 2)).await
+    }
+
+    /// Probes the default I2C addresses and initializes a display of the
+    /// given geometry.
+    async fn new_with_geometry(
+        mut i2c: I2C,
+        delay: D,
+        geometry: LcdGeometry,
+    ) -> Result<Self, LcdError<I2C::Error>> {
+        let address = Self::probe_address(&mut i2c).await.ok_or(LcdError::NotFound)?;
+        Self::new_with_address_and_geometry(i2c, delay, address, geometry).await
+    }
+
+    /// Initializes a 16x2 display at a known address, skipping the probe
+    /// scan entirely — the only option that is guaranteed not to touch
+    /// other devices on a shared bus.
+    async fn new_with_address(i2c: I2C, delay: D, address: u8) -> Result<Self, LcdError<I2C::Error>> {
+        Self::new_with_address_and_geometry(i2c, delay, address, LcdGeometry::new(16, 2)).await
+    }
+
+    /// Initializes a display of the given geometry at a known address,
+    /// skipping the probe scan entirely.
+    async fn new_with_address_and_geometry(
+        i2c: I2C,
+        delay: D,
+        address: u8,
+        geometry: LcdGeometry,
+    ) -> Result<Self, LcdError<I2C::Error>> {
+        let mut lcd = LcdI2cAsync {
+            i2c,
+            delay,
+            address,
+            backlight_state: BACKLIGHT,
+            geometry,
+            cursor_col: 0,
+            cursor_row: 0,
+            display_control: LCD_DISPLAYON | LCD_CURSOROFF | LCD_BLINKOFF,
+            entry_mode: LCD_ENTRYLEFT | LCD_ENTRYSHIFTDECREMENT,
+        };
+
+        for attempt in 0..3 {
+            if lcd.init().await.is_ok() {
+                return Ok(lcd);
+            }
+            lcd.delay_ms(100 * (attempt + 1) as u64).await;
+        }
+
+        Err(LcdError::InitFailed)
+    }
+
+    /// Finds the first responding address via a non-destructive
+    /// zero-length write, so other devices sharing the bus are left
+    /// undisturbed by the scan.
+    async fn probe_address(i2c: &mut I2C) -> Option<u8> {
+        for &addr in &LCD_ADDRESSES {
+            if i2c.write(addr, &[]).await.is_ok() {
+                return Some(addr);
+            }
+        }
+        None
+    }
+
+    async fn init(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.delay_ms(200).await;
+
+        self.backlight_test().await?;
+
+        for _ in 0..3 {
+            self.write_raw(0x00).await?;
+            self.delay_ms(10).await;
+
+            self.write_raw(0x30 | BACKLIGHT).await?;
+            self.pulse_enable_raw(0x30 | BACKLIGHT).await?;
+            self.delay_ms(50).await;
+
+            self.write_raw(0x30 | BACKLIGHT).await?;
+            self.pulse_enable_raw(0x30 | BACKLIGHT).await?;
+            self.delay_ms(10).await;
+
+            self.write_raw(0x30 | BACKLIGHT).await?;
+            self.pulse_enable_raw(0x30 | BACKLIGHT).await?;
+            self.delay_ms(5).await;
+
+            self.write_raw(0x20 | BACKLIGHT).await?;
+            self.pulse_enable_raw(0x20 | BACKLIGHT).await?;
+            self.delay_ms(5).await;
+
+            if self
+                .send_command(LCD_FUNCTIONSET | LCD_4BITMODE | LCD_2LINE | LCD_5X8DOTS)
+                .await
+                .is_ok()
+            {
+                break;
+            }
+            self.delay_ms(100).await;
+        }
+
+        self.send_command(LCD_DISPLAYCONTROL | LCD_DISPLAYOFF).await?;
+        self.send_command(LCD_CLEARDISPLAY).await?;
+        self.delay_ms(10).await;
+        self.send_command(LCD_ENTRYMODESET | self.entry_mode).await?;
+        self.send_command(LCD_DISPLAYCONTROL | self.display_control).await?;
+
+        Ok(())
+    }
+
+    async fn backlight_test(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.write_raw(0x00).await?;
+        self.delay_ms(100).await;
+        self.write_raw(BACKLIGHT).await?;
+        self.delay_ms(100).await;
+        Ok(())
+    }
+
+    async fn write_raw(&mut self, data: u8) -> Result<(), LcdError<I2C::Error>> {
+        self.i2c
+            .write(self.address, &[data])
+            .await
+            .map_err(LcdError::Bus)
+    }
+
+    async fn pulse_enable_raw(&mut self, data: u8) -> Result<(), LcdError<I2C::Error>> {
+        self.write_raw(data | EN).await?;
+        self.delay_us(2).await;
+        self.write_raw(data & !EN).await?;
+        self.delay_us(100).await;
+        Ok(())
+    }
+
+    async fn send_command(&mut self, cmd: u8) -> Result<(), LcdError<I2C::Error>> {
+        let (high, low) = command_nibbles(cmd, self.backlight_state);
+        self.write_raw(high).await?;
+        self.pulse_enable_raw(high).await?;
+
+        self.write_raw(low).await?;
+        self.pulse_enable_raw(low).await?;
+
+        match command_settle_delay(cmd) {
+            SettleDelay::Millis(ms) => self.delay_ms(ms).await,
+            SettleDelay::Micros(us) => self.delay_us(us).await,
+        }
+        Ok(())
+    }
+
+    async fn send_data(&mut self, data: u8) -> Result<(), LcdError<I2C::Error>> {
+        let (high, low) = data_nibbles(data, self.backlight_state);
+        self.write_raw(high).await?;
+        self.pulse_enable_raw(high).await?;
+
+        self.write_raw(low).await?;
+        self.pulse_enable_raw(low).await?;
+
+        self.delay_us(200).await;
+        Ok(())
+    }
+
+    async fn clear(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.send_command(LCD_CLEARDISPLAY).await?;
+        self.delay_ms(5).await;
+        self.cursor_col = 0;
+        self.cursor_row = 0;
+        Ok(())
+    }
+
+    async fn set_cursor(&mut self, col: u8, row: u8) -> Result<(), LcdError<I2C::Error>> {
+        if row < self.geometry.rows && col < self.geometry.cols {
+            let pos = col + self.geometry.row_offset(row);
+            self.send_command(LCD_SETDDRAMADDR | pos).await?;
+            self.cursor_col = col;
+            self.cursor_row = row;
+        }
+        Ok(())
+    }
+
+    /// Advances the tracked cursor position to the start of the next line,
+    /// wrapping back to row 0 once past the last row.
+    async fn advance_line(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        let next_row = (self.cursor_row + 1) % self.geometry.rows;
+        self.set_cursor(0, next_row).await
+    }
+
+    /// Emits one character and keeps `cursor_col`/`cursor_row` in sync,
+    /// auto-wrapping at the configured width. This is the single place
+    /// that advances the tracked cursor, so `print`/`print_at` and
+    /// [`Self::write_str`] never disagree about where it is.
+    async fn put_char(&mut self, byte: u8) -> Result<(), LcdError<I2C::Error>> {
+        if byte == b'\n' {
+            return self.advance_line().await;
+        }
+
+        self.send_data(byte).await?;
+        self.cursor_col += 1;
+
+        if self.cursor_col >= self.geometry.cols {
+            self.advance_line().await?;
+        }
+        Ok(())
+    }
+
+    /// Async analogue of `core::fmt::Write::write_str`. It is an inherent
+    /// method rather than a trait impl because `core::fmt::Write` is
+    /// synchronous and cannot `.await`. It writes text that is already a
+    /// `&str`; `write!`/`format_args!` output cannot be passed to it
+    /// directly and has to be rendered into a string first.
+    async fn write_str(&mut self, s: &str) -> Result<(), LcdError<I2C::Error>> {
+        for byte in s.bytes() {
+            self.put_char(byte).await?;
+        }
+        Ok(())
+    }
+
+    /// Loads a user-defined 5x8 glyph into one of the eight CGRAM slots.
+    ///
+    /// `pattern` holds one byte per glyph row; only the low 5 bits of each
+    /// byte are significant. The previous DDRAM cursor position is saved
+    /// and restored afterwards so printing genuinely resumes where it
+    /// left off. Use `send_data(slot)` to display it.
+    async fn create_char(&mut self, slot: u8, pattern: &[u8; 8]) -> Result<(), LcdError<I2C::Error>> {
+        if slot >= 8 {
+            return Err(LcdError::InvalidSlot);
+        }
+
+        let (prev_col, prev_row) = (self.cursor_col, self.cursor_row);
+
+        self.send_command(LCD_SETCGRAMADDR | (slot << 3)).await?;
+        for &row in pattern {
+            self.send_data(row & 0x1F).await?;
+        }
+
+        self.set_cursor(prev_col, prev_row).await?;
+        Ok(())
+    }
+
+    async fn print(&mut self, text: &str) -> Result<(), LcdError<I2C::Error>> {
+        for byte in text.bytes() {
+            self.put_char(byte).await?;
+        }
+        Ok(())
+    }
+
+    async fn print_at(&mut self, col: u8, row: u8, text: &str) -> Result<(), LcdError<I2C::Error>> {
+        self.set_cursor(col, row).await?;
+        self.print(text).await
+    }
+
+    fn get_address(&self) -> u8 {
+        self.address
+    }
+
+    /// Turns the display on or off without affecting cursor/blink state.
+    async fn set_display(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.set_display_control_bit(LCD_DISPLAYON, on).await
+    }
+
+    /// Shows or hides the underline cursor.
+    async fn set_cursor_visible(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.set_display_control_bit(LCD_CURSORON, on).await
+    }
+
+    /// Enables or disables the blinking block cursor.
+    async fn set_blink(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.set_display_control_bit(LCD_BLINKON, on).await
+    }
+
+    /// Enables or disables autoscroll on write (shifts existing text instead
+    /// of advancing the cursor).
+    async fn set_autoscroll(&mut self, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.entry_mode = if on {
+            self.entry_mode | LCD_ENTRYSHIFTINCREMENT
+        } else {
+            self.entry_mode & !LCD_ENTRYSHIFTINCREMENT
+        };
+        self.send_command(LCD_ENTRYMODESET | self.entry_mode).await
+    }
+
+    /// Shifts the whole display one position to the left.
+    async fn scroll_display_left(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.send_command(LCD_CURSORSHIFT | LCD_DISPLAYMOVE | LCD_MOVELEFT).await
+    }
+
+    /// Shifts the whole display one position to the right.
+    async fn scroll_display_right(&mut self) -> Result<(), LcdError<I2C::Error>> {
+        self.send_command(LCD_CURSORSHIFT | LCD_DISPLAYMOVE | LCD_MOVERIGHT).await
+    }
+
+    /// Sets or clears a bit in the cached display-control byte and re-sends
+    /// the composite command so the other flags are left untouched.
+    async fn set_display_control_bit(&mut self, bit: u8, on: bool) -> Result<(), LcdError<I2C::Error>> {
+        self.display_control = if on {
+            self.display_control | bit
+        } else {
+            self.display_control & !bit
+        };
+        self.send_command(LCD_DISPLAYCONTROL | self.display_control).await
+    }
+
+    async fn delay_ms(&mut self, ms: u64) {
+        self.delay.delay_ms(ms as u32).await;
+    }
+
+    async fn delay_us(&mut self, us: u64) {
+        self.delay.delay_us(us as u32).await;
+    }
+}
+
 fn blink_led(led: &mut Output, count: u8, on_ms: u64, off_ms: u64) {
     for _ in 0..count {
         led.set_high();
@@ -302,21 +878,12 @@ fn main() -> ! {
         if let Err(_) = lcd.print_at(0, 0, "I2C configured!") {
             blink_led(&mut led, 2, 50, 50);
         }
-        
-        let ones = (counter % 10) as u8 + b'0';
-        let tens = ((counter / 10) % 10) as u8 + b'0';
-        let hundreds = ((counter / 100) % 10) as u8 + b'0';
-        
-        if let Err(_) = lcd.print_at(0, 1, "Count: ") {
-            blink_led(&mut led, 2, 50, 50);
-        }
-        if let Err(_) = lcd.send_data(hundreds) {
-            blink_led(&mut led, 2, 50, 50);
-        }
-        if let Err(_) = lcd.send_data(tens) {
-            blink_led(&mut led, 2, 50, 50);
-        }
-        if let Err(_) = lcd.send_data(ones) {
+
+        if lcd.set_cursor(0, 1).is_ok() {
+            if let Err(_) = write!(lcd, "Count: {}", counter) {
+                blink_led(&mut led, 2, 50, 50);
+            }
+        } else {
             blink_led(&mut led, 2, 50, 50);
         }
         